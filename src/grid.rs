@@ -0,0 +1,156 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::point::Point3;
+
+/// A sparse grid keyed by [`Point3`], the shape most AoC puzzle inputs actually take.
+/// Tracks its populated bounding box incrementally so callers don't have to scan the
+/// whole map to know how big it is.
+pub struct HashGrid<T> {
+    cells: HashMap<Point3, T>,
+    min: Point3,
+    max: Point3,
+}
+
+impl<T> HashGrid<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            min: Point3::new(i64::MAX, i64::MAX, i64::MAX),
+            max: Point3::new(i64::MIN, i64::MIN, i64::MIN),
+        }
+    }
+
+    /// Parses a multiline string into `(x, y)` cells, row index as `y` and column as
+    /// `x` (`z` is always `0`), calling `f` once per character.
+    #[must_use]
+    pub fn from_str_2d(raw: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut grid = Self::new();
+        for (y, line) in raw.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                grid.insert((x as i64, y as i64), f(c));
+            }
+        }
+        grid
+    }
+
+    #[must_use]
+    pub fn get(&self, p: &Point3) -> Option<&T> {
+        self.cells.get(p)
+    }
+
+    pub fn insert(&mut self, p: impl Into<Point3>, value: T) {
+        let p = p.into();
+        self.track_bounds(p);
+        self.cells.insert(p, value);
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The `(min, max)` corners of the populated region, inclusive.
+    #[must_use]
+    pub fn bounds(&self) -> (Point3, Point3) {
+        (self.min, self.max)
+    }
+
+    /// The populated plane (orthogonal) neighbors of `p`.
+    pub fn neighbors_of<'a, 'b>(
+        &'a self,
+        p: &'b Point3,
+    ) -> impl Iterator<Item = &'a T> + use<'a, 'b, T> {
+        p.plane_neighbors().filter_map(move |n| self.get(&n))
+    }
+
+    /// The populated Moore neighbors of `p`: all 26 surrounding cells, including the
+    /// layers above and below, so on a single-layer 2D grid these are the 8 in-plane ones.
+    pub fn moore_neighbors_of<'a, 'b>(
+        &'a self,
+        p: &'b Point3,
+    ) -> impl Iterator<Item = &'a T> + use<'a, 'b, T> {
+        p.neighbors().filter_map(move |n| self.get(&n))
+    }
+
+    fn track_bounds(&mut self, p: Point3) {
+        self.min = Point3::new(
+            self.min.x().min(p.x()),
+            self.min.y().min(p.y()),
+            self.min.z().min(p.z()),
+        );
+        self.max = Point3::new(
+            self.max.x().max(p.x()),
+            self.max.y().max(p.y()),
+            self.max.z().max(p.z()),
+        );
+    }
+}
+
+impl<T> Default for HashGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Display> Display for HashGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        for y in self.min.y()..=self.max.y() {
+            for x in self.min.x()..=self.max.x() {
+                match self.get(&Point3::new(x, y, self.min.z())) {
+                    Some(v) => write!(f, "{v}")?,
+                    None => write!(f, " ")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_str_2d_tracks_bounds_and_cells() {
+        let grid = HashGrid::from_str_2d("#.\n.#", |c| c);
+        assert_eq!(grid.len(), 4);
+        assert_eq!(
+            grid.bounds(),
+            (Point3::new(0, 0, 0), Point3::new(1, 1, 0))
+        );
+        assert_eq!(grid.get(&Point3::new(1, 1, 0)), Some(&'#'));
+    }
+
+    #[test]
+    fn neighbors_of_filters_out_of_grid() {
+        let grid = HashGrid::from_str_2d("ab", |c| c);
+        let neighbors: Vec<_> = grid.neighbors_of(&Point3::new(0, 0, 0)).collect();
+        assert_eq!(neighbors, vec![&'b']);
+    }
+
+    #[test]
+    fn moore_neighbors_of_includes_diagonals() {
+        let grid = HashGrid::from_str_2d("ab\ncd", |c| c);
+        let mut neighbors: Vec<_> = grid.moore_neighbors_of(&Point3::new(0, 0, 0)).collect();
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![&'b', &'c', &'d']);
+    }
+
+    #[test]
+    fn display_renders_populated_region() {
+        let grid = HashGrid::from_str_2d("#.\n.#", |c| c);
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+    }
+}
@@ -4,258 +4,540 @@ use std::{
     ops::{Add, AddAssign, Div, Mul, Neg, Sub},
 };
 
+/// An integer point/vector in `D`-dimensional space, backed by a fixed-size array of
+/// coordinates. Most AoC puzzles live in 2D or 3D; see [`Point3`] for the concrete
+/// alias those cases use.
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy, PartialOrd)]
-pub struct Point {
-    pub x: i64,
-    pub y: i64,
-    pub z: i64,
+pub struct Point<const D: usize> {
+    pub coords: [i64; D],
 }
 
-impl Display for Point {
+/// The common 3D case, which carries the `x`/`y`/`z`-flavored helpers that don't make
+/// sense for an arbitrary `D` (compass directions, the standard basis vectors, ...).
+pub type Point3 = Point<3>;
+
+impl<const D: usize> Display for Point<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("({}, {}, {})", self.x, self.y, self.z))
+        f.write_str("(")?;
+        for (i, c) in self.coords.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{c}")?;
+        }
+        f.write_str(")")
     }
 }
 
-impl Div<i64> for Point {
-    type Output = Point;
+impl<const D: usize> Div<i64> for Point<D> {
+    type Output = Point<D>;
 
     fn div(self, rhs: i64) -> Self::Output {
         Point {
-            x: self.x / rhs,
-            y: self.y / rhs,
-            z: self.z / rhs,
+            coords: self.coords.map(|c| c / rhs),
         }
     }
 }
 
-impl Mul<i64> for Point {
-    type Output = Point;
+impl<const D: usize> Mul<i64> for Point<D> {
+    type Output = Point<D>;
 
     fn mul(self, rhs: i64) -> Self::Output {
         Point {
-            x: self.x * rhs,
-            y: self.y * rhs,
-            z: self.z * rhs,
+            coords: self.coords.map(|c| c * rhs),
         }
     }
 }
 
-impl Mul<Point> for Point {
+impl<const D: usize> Mul<Point<D>> for Point<D> {
     type Output = i64;
 
-    fn mul(self, rhs: Point) -> Self::Output {
-        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    fn mul(self, rhs: Point<D>) -> Self::Output {
+        (0..D).map(|i| self.coords[i] * rhs.coords[i]).sum()
     }
 }
 
-impl Add<Point> for Point {
-    type Output = Point;
+impl<const D: usize> Add<Point<D>> for Point<D> {
+    type Output = Point<D>;
 
-    fn add(self, rhs: Point) -> Self::Output {
+    fn add(self, rhs: Point<D>) -> Self::Output {
         Point {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
+            coords: std::array::from_fn(|i| self.coords[i] + rhs.coords[i]),
         }
     }
 }
 
-impl Add<&Point> for Point {
-    type Output = Point;
+impl<const D: usize> Add<&Point<D>> for Point<D> {
+    type Output = Point<D>;
 
-    fn add(self, rhs: &Point) -> Self::Output {
+    fn add(self, rhs: &Point<D>) -> Self::Output {
         Point {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
+            coords: std::array::from_fn(|i| self.coords[i] + rhs.coords[i]),
         }
     }
 }
 
-impl AddAssign<Point> for Point {
-    fn add_assign(&mut self, rhs: Point) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
+impl<const D: usize> AddAssign<Point<D>> for Point<D> {
+    fn add_assign(&mut self, rhs: Point<D>) {
+        for i in 0..D {
+            self.coords[i] += rhs.coords[i];
+        }
     }
 }
 
-impl Sub<Point> for Point {
-    type Output = Point;
+impl<const D: usize> Sub<Point<D>> for Point<D> {
+    type Output = Point<D>;
 
-    fn sub(self, rhs: Point) -> Self::Output {
+    fn sub(self, rhs: Point<D>) -> Self::Output {
         Point {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
+            coords: std::array::from_fn(|i| self.coords[i] - rhs.coords[i]),
         }
     }
 }
 
-impl Sub<&Point> for Point {
-    type Output = Point;
+impl<const D: usize> Sub<&Point<D>> for Point<D> {
+    type Output = Point<D>;
 
-    fn sub(self, rhs: &Point) -> Self::Output {
+    fn sub(self, rhs: &Point<D>) -> Self::Output {
         Point {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
+            coords: std::array::from_fn(|i| self.coords[i] - rhs.coords[i]),
         }
     }
 }
 
-impl AddAssign<&Point> for Point {
-    fn add_assign(&mut self, rhs: &Point) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
+impl<const D: usize> AddAssign<&Point<D>> for Point<D> {
+    fn add_assign(&mut self, rhs: &Point<D>) {
+        for i in 0..D {
+            self.coords[i] += rhs.coords[i];
+        }
     }
 }
 
-impl Neg for Point {
-    type Output = Point;
+impl<const D: usize> Neg for Point<D> {
+    type Output = Point<D>;
 
     fn neg(self) -> Self::Output {
         Point {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z,
+            coords: self.coords.map(|c| -c),
         }
     }
 }
 
-impl FromIterator<i64> for Point {
+impl<const D: usize> FromIterator<i64> for Point<D> {
+    /// # Panics
+    /// Panics if `iter` yields fewer than `D` elements; missing axes are never
+    /// zero-filled.
     fn from_iter<T: IntoIterator<Item = i64>>(iter: T) -> Self {
         let mut iter = iter.into_iter();
         Point {
-            x: iter.next().unwrap(),
-            y: iter.next().unwrap(),
-            z: iter.next().unwrap_or(0),
+            coords: std::array::from_fn(|_| iter.next().expect("not enough coordinates for Point<D>")),
         }
     }
 }
 
-impl Point {
-    const ORIGIN: Point = Point { x: 0, y: 0, z: 0 };
-    const I: Point = Point { x: 1, y: 0, z: 0 };
-    const J: Point = Point { x: 0, y: 1, z: 0 };
-    const K: Point = Point { x: 0, y: 0, z: 1 };
-    const ZERO: Point = Point { x: 0, y: 0, z: 0 };
+impl<const D: usize> Point<D> {
+    const ZERO: Point<D> = Point { coords: [0; D] };
+
+    #[must_use]
+    pub const fn from_coords(coords: [i64; D]) -> Self {
+        Self { coords }
+    }
 
     #[must_use]
     pub fn origin() -> &'static Self {
-        &Self::ORIGIN
+        &Self::ZERO
+    }
+
+    #[must_use]
+    pub fn len_squared(&self) -> i64 {
+        self.coords.iter().map(|c| c.pow(2)).sum()
+    }
+
+    #[must_use]
+    pub fn distance_squared(&self, other: &Point<D>) -> i64 {
+        (0..D).map(|i| (self.coords[i] - other.coords[i]).pow(2)).sum()
+    }
+
+    #[must_use]
+    pub fn manhattan_distance(&self, other: &Point<D>) -> i64 {
+        (0..D)
+            .map(|i| (self.coords[i] - other.coords[i]).abs())
+            .sum()
+    }
+
+    #[must_use]
+    pub fn chebyshev_distance(&self, other: &Point<D>) -> i64 {
+        (0..D)
+            .map(|i| (self.coords[i] - other.coords[i]).abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The exact floor of the Euclidean length of `self`, computed from
+    /// [`Point::len_squared`] via an integer square root so it is never off by the
+    /// truncation error a `f64::sqrt` round-trip would introduce.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_wrap)]
+    #[must_use]
+    pub fn integral_norm(&self) -> i64 {
+        isqrt(self.len_squared() as u64) as i64
+    }
+
+    /// The per-axis sign vector (`-1`, `0` or `1` on each coordinate). This is what
+    /// most AoC line/step puzzles actually want out of a "direction", and unlike
+    /// [`Point3::normalized`] it never distorts diagonal steps.
+    #[must_use]
+    pub fn signum(&self) -> Point<D> {
+        Point {
+            coords: self.coords.map(i64::signum),
+        }
+    }
+
+    /// `self` plus every offset vector in `{-1,0,1}^D` except the all-zero vector, i.e.
+    /// the full Moore neighborhood of `self` (`3.pow(D) - 1` points).
+    #[must_use]
+    pub fn neighbors(&self) -> Neighbors<'_, D> {
+        Neighbors::new(self)
+    }
+}
+
+impl From<(i64, i64)> for Point3 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point3::new(x, y, 0)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Point3::new(x, y, z)
     }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point { coords: [0, 0, 0] };
+    pub const I: Point3 = Point { coords: [1, 0, 0] };
+    pub const J: Point3 = Point { coords: [0, 1, 0] };
+    pub const K: Point3 = Point { coords: [0, 0, 1] };
+
+    /// The 24 orientation-preserving (determinant `+1`) signed-permutation matrices of
+    /// the cube, row-major, for use with [`Point3::transform`]. These are every way a
+    /// die can be picked up and set back down, which is exactly the ambiguity
+    /// scanner/tile-reassembly puzzles need to search over.
+    pub const ROTATIONS: [[i64; 9]; 24] = [
+        [1, 0, 0, 0, 1, 0, 0, 0, 1],
+        [1, 0, 0, 0, -1, 0, 0, 0, -1],
+        [-1, 0, 0, 0, 1, 0, 0, 0, -1],
+        [-1, 0, 0, 0, -1, 0, 0, 0, 1],
+        [-1, 0, 0, 0, 0, 1, 0, 1, 0],
+        [1, 0, 0, 0, 0, -1, 0, 1, 0],
+        [1, 0, 0, 0, 0, 1, 0, -1, 0],
+        [-1, 0, 0, 0, 0, -1, 0, -1, 0],
+        [0, -1, 0, 1, 0, 0, 0, 0, 1],
+        [0, 1, 0, -1, 0, 0, 0, 0, 1],
+        [0, 1, 0, 1, 0, 0, 0, 0, -1],
+        [0, -1, 0, -1, 0, 0, 0, 0, -1],
+        [0, 1, 0, 0, 0, 1, 1, 0, 0],
+        [0, 1, 0, 0, 0, -1, -1, 0, 0],
+        [0, -1, 0, 0, 0, 1, -1, 0, 0],
+        [0, -1, 0, 0, 0, -1, 1, 0, 0],
+        [0, 0, 1, 1, 0, 0, 0, 1, 0],
+        [0, 0, 1, -1, 0, 0, 0, -1, 0],
+        [0, 0, -1, 1, 0, 0, 0, -1, 0],
+        [0, 0, -1, -1, 0, 0, 0, 1, 0],
+        [0, 0, -1, 0, 1, 0, 1, 0, 0],
+        [0, 0, 1, 0, -1, 0, 1, 0, 0],
+        [0, 0, 1, 0, 1, 0, -1, 0, 0],
+        [0, 0, -1, 0, -1, 0, -1, 0, 0],
+    ];
 
     #[must_use]
     pub const fn new(x: i64, y: i64, z: i64) -> Self {
-        Self { x, y, z }
+        Self { coords: [x, y, z] }
+    }
+
+    /// Applies a row-major `3x3` integer matrix to `self`.
+    #[must_use]
+    pub fn transform(&self, m: &[i64; 9]) -> Point3 {
+        Point3::new(
+            m[0] * self.x() + m[1] * self.y() + m[2] * self.z(),
+            m[3] * self.x() + m[4] * self.y() + m[5] * self.z(),
+            m[6] * self.x() + m[7] * self.y() + m[8] * self.z(),
+        )
+    }
+
+    /// `self` under all 24 orientation-preserving rotations of the cube.
+    pub fn orientations(&self) -> impl Iterator<Item = Point3> + '_ {
+        Self::ROTATIONS.iter().map(move |m| self.transform(m))
+    }
+
+    /// Rotates `self` 90° counter-clockwise about the z-axis: `(x, y) -> (-y, x)`.
+    #[must_use]
+    pub fn rotate_left(&self) -> Point3 {
+        Point3::new(-self.y(), self.x(), self.z())
+    }
+
+    /// Rotates `self` 90° clockwise about the z-axis: `(x, y) -> (y, -x)`.
+    #[must_use]
+    pub fn rotate_right(&self) -> Point3 {
+        Point3::new(self.y(), -self.x(), self.z())
+    }
+
+    #[must_use]
+    pub fn x(&self) -> i64 {
+        self.coords[0]
+    }
+
+    #[must_use]
+    pub fn y(&self) -> i64 {
+        self.coords[1]
+    }
+
+    #[must_use]
+    pub fn z(&self) -> i64 {
+        self.coords[2]
     }
 
     /// # Panics
     /// Panics if the input is the zero vector which cannot be normalized as it has no dimension
+    #[deprecated(note = "loses direction on diagonals; use `signum` or `integral_norm` instead")]
     #[allow(clippy::cast_precision_loss)]
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
-    pub fn normalized(&self) -> Point {
-        assert!(*self != Self::ZERO, "Cannot normalize zero vector!");
+    pub fn normalized(&self) -> Point3 {
+        assert!(*self != Self::ORIGIN, "Cannot normalize zero vector!");
         *self / (self.len_squared() as f64).sqrt() as i64
     }
 
     #[must_use]
-    pub fn distance_squared(&self, other: &Point) -> i64 {
-        (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)
+    pub fn plane_neighbors(&self) -> PlaneNeighbours<'_> {
+        PlaneNeighbours::new(self)
     }
 
     #[must_use]
-    pub fn manhattan_distance(&self, other: &Point) -> i64 {
-        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    pub fn points_between(&self, other: &Point3) -> PointsBetween {
+        PointsBetween::new(*self, *other)
     }
 
     #[must_use]
-    pub fn plane_neighbors(&self) -> PlaneNeighbours<'_> {
-        PlaneNeighbours::new(self)
+    pub fn down(&self) -> Self {
+        Point::new(self.x(), self.y() - 1, self.z())
     }
 
     #[must_use]
-    pub fn points_between(&self, other: &Point) -> PointsBetween {
-        PointsBetween::new(*self, *other)
+    pub fn up(&self) -> Self {
+        Point::new(self.x(), self.y() + 1, self.z())
     }
 
     #[must_use]
-    pub fn len_squared(&self) -> i64 {
-        self.x.pow(2) + self.y.pow(2) + self.z.pow(2)
+    pub fn left(&self) -> Self {
+        Point::new(self.x() - 1, self.y(), self.z())
     }
 
     #[must_use]
-    pub fn down(&self) -> Self {
-        Point {
-            x: self.x,
-            y: self.y - 1,
-            z: self.z,
-        }
+    pub fn right(&self) -> Self {
+        Point::new(self.x() + 1, self.y(), self.z())
     }
 
+    /// Moves `n` units along `dir`, so callers can write `p.step(dir, 3)` instead of
+    /// reimplementing direction arithmetic by hand.
     #[must_use]
-    pub fn up(&self) -> Self {
-        Point {
-            x: self.x,
-            y: self.y + 1,
-            z: self.z,
+    pub fn step(&self, dir: Direction, n: i64) -> Point3 {
+        *self + dir.to_point() * n
+    }
+}
+
+/// Exact integer square root via the classic bit-by-bit method: starting from the
+/// highest even power of two `<= n`, greedily fold each bit into the running result
+/// whenever doing so doesn't overshoot `n`. Overflow-safe and needs no floating point.
+fn isqrt(n: u64) -> u64 {
+    let mut bit: u64 = 1 << 62;
+    while bit > n {
+        bit >>= 2;
+    }
+
+    let mut result = 0u64;
+    let mut n = n;
+    while bit != 0 {
+        if n >= result + bit {
+            n -= result + bit;
+            result = (result >> 1) + bit;
+        } else {
+            result >>= 1;
         }
+        bit >>= 2;
     }
 
-    #[must_use]
-    pub fn left(&self) -> Self {
-        Point {
-            x: self.x - 1,
-            y: self.y,
-            z: self.z,
+    result
+}
+
+pub struct Neighbors<'a, const D: usize> {
+    p: &'a Point<D>,
+    idx: usize,
+    total: usize,
+}
+
+impl<'a, const D: usize> Neighbors<'a, D> {
+    fn new(p: &'a Point<D>) -> Self {
+        Self {
+            p,
+            idx: 0,
+            total: 3usize.pow(D as u32),
         }
     }
+}
 
-    #[must_use]
-    pub fn right(&self) -> Self {
-        Point {
-            x: self.x + 1,
-            y: self.y,
-            z: self.z,
+impl<const D: usize> Iterator for Neighbors<'_, D> {
+    type Item = Point<D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.idx >= self.total {
+                return None;
+            }
+
+            let mut n = self.idx;
+            self.idx += 1;
+
+            let mut offset = [0i64; D];
+            for trit in &mut offset {
+                *trit = (n % 3) as i64 - 1;
+                n /= 3;
+            }
+
+            if offset.iter().all(|&c| c == 0) {
+                continue;
+            }
+
+            return Some(Point {
+                coords: std::array::from_fn(|i| self.p.coords[i] + offset[i]),
+            });
         }
     }
 }
 
-pub struct PointsBetween {
-    current: Point,
-    end: Point,
-    step: Point,
-    done: bool,
+pub struct PointsBetween(PointsBetweenKind);
+
+enum PointsBetweenKind {
+    Bresenham(BresenhamWalk),
+    Stepped(SteppedWalk),
 }
 
 impl PointsBetween {
+    /// Walks every integer lattice point from `start` to `end` inclusive, via a 3D
+    /// Bresenham/DDA line: the driving axis (the one with the largest delta) advances
+    /// every step, while the other two axes accumulate error and advance whenever it
+    /// goes non-negative. Unlike a `normalized()`-derived step, this handles any slope.
     #[must_use]
-    pub fn new(start: Point, end: Point) -> PointsBetween {
-        let step = (end - start).normalized();
-        Self {
+    pub fn new(start: Point3, end: Point3) -> PointsBetween {
+        PointsBetween(PointsBetweenKind::Bresenham(BresenhamWalk::new(
+            start, end,
+        )))
+    }
+
+    /// # Panics
+    /// Panics if `step` is the zero vector, since it would never reach `end`.
+    #[must_use]
+    pub fn with_step(start: Point3, end: Point3, step: Point3) -> PointsBetween {
+        assert!(
+            step != Point3::ORIGIN,
+            "PointsBetween::with_step: step must not be the zero vector"
+        );
+        PointsBetween(PointsBetweenKind::Stepped(SteppedWalk {
             current: start,
             end: end + step,
             step,
             done: false,
+        }))
+    }
+}
+
+impl Iterator for PointsBetween {
+    type Item = Point3;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            PointsBetweenKind::Bresenham(walk) => walk.next(),
+            PointsBetweenKind::Stepped(walk) => walk.next(),
         }
     }
+}
+
+struct BresenhamWalk {
+    current: Point3,
+    signs: [i64; 3],
+    deltas: [i64; 3],
+    driving: usize,
+    minor_a: usize,
+    minor_b: usize,
+    err_a: i64,
+    err_b: i64,
+    remaining: i64,
+}
+
+impl BresenhamWalk {
+    fn new(start: Point3, end: Point3) -> Self {
+        let delta = end - start;
+        let deltas = [delta.x().abs(), delta.y().abs(), delta.z().abs()];
+        let signs = [delta.x().signum(), delta.y().signum(), delta.z().signum()];
+
+        let driving = (0..3).max_by_key(|&i| deltas[i]).unwrap();
+        let mut minors = (0..3).filter(|&i| i != driving);
+        let minor_a = minors.next().unwrap();
+        let minor_b = minors.next().unwrap();
 
-    pub fn with_step(start: Point, end: Point, step: Point) -> PointsBetween {
         Self {
             current: start,
-            end: end + step,
-            step,
-            done: false,
+            signs,
+            deltas,
+            driving,
+            minor_a,
+            minor_b,
+            err_a: 2 * deltas[minor_a] - deltas[driving],
+            err_b: 2 * deltas[minor_b] - deltas[driving],
+            remaining: deltas[driving] + 1,
         }
     }
 }
 
-impl Iterator for PointsBetween {
-    type Item = Point;
+impl Iterator for BresenhamWalk {
+    type Item = Point3;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining <= 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let result = self.current;
+        let mut coords = [self.current.x(), self.current.y(), self.current.z()];
+        coords[self.driving] += self.signs[self.driving];
+
+        if self.err_a >= 0 {
+            coords[self.minor_a] += self.signs[self.minor_a];
+            self.err_a -= 2 * self.deltas[self.driving];
+        }
+        if self.err_b >= 0 {
+            coords[self.minor_b] += self.signs[self.minor_b];
+            self.err_b -= 2 * self.deltas[self.driving];
+        }
+        self.err_a += 2 * self.deltas[self.minor_a];
+        self.err_b += 2 * self.deltas[self.minor_b];
+
+        self.current = Point3::new(coords[0], coords[1], coords[2]);
+
+        Some(result)
+    }
+}
+
+struct SteppedWalk {
+    current: Point3,
+    end: Point3,
+    step: Point3,
+    done: bool,
+}
+
+impl Iterator for SteppedWalk {
+    type Item = Point3;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.done {
@@ -274,13 +556,13 @@ impl Iterator for PointsBetween {
 }
 
 pub struct PlaneNeighbours<'a> {
-    p: &'a Point,
+    p: &'a Point3,
     dir_idx: usize,
-    _phantom: PhantomData<&'a Point>,
+    _phantom: PhantomData<&'a Point3>,
 }
 
 impl PlaneNeighbours<'_> {
-    fn new(p: &Point) -> PlaneNeighbours<'_> {
+    fn new(p: &Point3) -> PlaneNeighbours<'_> {
         PlaneNeighbours {
             p,
             dir_idx: 0,
@@ -290,7 +572,7 @@ impl PlaneNeighbours<'_> {
 }
 
 impl Iterator for PlaneNeighbours<'_> {
-    type Item = Point;
+    type Item = Point3;
 
     fn next(&mut self) -> Option<Self::Item> {
         let res = Direction::plane()
@@ -310,6 +592,10 @@ pub enum Direction {
     NegY,
     PosZ,
     NegZ,
+    PosXPosY,
+    PosXNegY,
+    NegXPosY,
+    NegXNegY,
 }
 
 impl Direction {
@@ -320,15 +606,39 @@ impl Direction {
         Direction::NegY,
     ];
 
+    const DIAGONALS: [Direction; 4] = [
+        Direction::PosXPosY,
+        Direction::PosXNegY,
+        Direction::NegXPosY,
+        Direction::NegXNegY,
+    ];
+
+    /// The eight king-move directions, in rotational (clockwise, since `y` grows
+    /// downward here and `-y` is north) order.
+    const PLANE_WITH_DIAGONALS: [Direction; 8] = [
+        Direction::PosX,
+        Direction::PosXPosY,
+        Direction::PosY,
+        Direction::NegXPosY,
+        Direction::NegX,
+        Direction::NegXNegY,
+        Direction::NegY,
+        Direction::PosXNegY,
+    ];
+
     #[must_use]
-    pub fn to_point(&self) -> Point {
+    pub fn to_point(&self) -> Point3 {
         match self {
-            Direction::PosX => Point::I,
-            Direction::NegX => -Point::I,
-            Direction::PosY => Point::J,
-            Direction::NegY => -Point::J,
-            Direction::PosZ => Point::K,
-            Direction::NegZ => -Point::K,
+            Direction::PosX => Point3::I,
+            Direction::NegX => -Point3::I,
+            Direction::PosY => Point3::J,
+            Direction::NegY => -Point3::J,
+            Direction::PosZ => Point3::K,
+            Direction::NegZ => -Point3::K,
+            Direction::PosXPosY => Point3::I + Point3::J,
+            Direction::PosXNegY => Point3::I - Point3::J,
+            Direction::NegXPosY => Point3::J - Point3::I,
+            Direction::NegXNegY => -Point3::I - Point3::J,
         }
     }
 
@@ -336,15 +646,216 @@ impl Direction {
     pub fn plane() -> &'static [Direction; 4] {
         &Self::PLANE
     }
+
+    #[must_use]
+    pub fn diagonals() -> &'static [Direction; 4] {
+        &Self::DIAGONALS
+    }
+
+    #[must_use]
+    pub fn all_plane_with_diagonals() -> &'static [Direction; 8] {
+        &Self::PLANE_WITH_DIAGONALS
+    }
+
+    /// The direction pointing the opposite way.
+    #[must_use]
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::PosX => Direction::NegX,
+            Direction::NegX => Direction::PosX,
+            Direction::PosY => Direction::NegY,
+            Direction::NegY => Direction::PosY,
+            Direction::PosZ => Direction::NegZ,
+            Direction::NegZ => Direction::PosZ,
+            Direction::PosXPosY => Direction::NegXNegY,
+            Direction::NegXNegY => Direction::PosXPosY,
+            Direction::PosXNegY => Direction::NegXPosY,
+            Direction::NegXPosY => Direction::PosXNegY,
+        }
+    }
+
+    /// Rotates 90° counter-clockwise on the map (`y` grows downward here, so `-y` is
+    /// north), cycling `PosX -> NegY -> NegX -> PosY` i.e. East -> North -> West ->
+    /// South. The diagonals rotate the same way around the z-axis.
+    #[must_use]
+    pub fn turn_left(&self) -> Direction {
+        Self::from_point(self.to_point().rotate_right())
+    }
+
+    /// Rotates 90° clockwise on the map, the reverse of [`Direction::turn_left`].
+    #[must_use]
+    pub fn turn_right(&self) -> Direction {
+        Self::from_point(self.to_point().rotate_left())
+    }
+
+    /// Parses `U/D/L/R`, `N/S/E/W` or `^v<>`, the three notations AoC inputs use for
+    /// plane directions.
+    #[must_use]
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' | 'N' | '^' => Some(Direction::NegY),
+            'D' | 'S' | 'v' => Some(Direction::PosY),
+            'L' | 'W' | '<' => Some(Direction::NegX),
+            'R' | 'E' | '>' => Some(Direction::PosX),
+            _ => None,
+        }
+    }
+
+    fn from_point(p: Point3) -> Direction {
+        match (p.x(), p.y(), p.z()) {
+            (1, 0, 0) => Direction::PosX,
+            (-1, 0, 0) => Direction::NegX,
+            (0, 1, 0) => Direction::PosY,
+            (0, -1, 0) => Direction::NegY,
+            (0, 0, 1) => Direction::PosZ,
+            (0, 0, -1) => Direction::NegZ,
+            (1, 1, 0) => Direction::PosXPosY,
+            (1, -1, 0) => Direction::PosXNegY,
+            (-1, 1, 0) => Direction::NegXPosY,
+            (-1, -1, 0) => Direction::NegXNegY,
+            _ => unreachable!("rotating a unit direction never leaves the unit set"),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+
     #[test]
     fn distance() {
-        let a = Point::new(3, 0, 0);
-        let b = Point::new(4, 0, 0);
+        let a = Point3::new(3, 0, 0);
+        let b = Point3::new(4, 0, 0);
         assert!(a.distance_squared(&b) == 1);
     }
+
+    #[test]
+    fn from_iter_collects_exactly_d_coordinates() {
+        let p: Point3 = [1, 2, 3].into_iter().collect();
+        assert_eq!(p, Point3::new(1, 2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough coordinates")]
+    fn from_iter_panics_on_too_few_coordinates() {
+        let _: Point3 = [1, 2].into_iter().collect();
+    }
+
+    #[test]
+    fn neighbors_2d_is_eight() {
+        let p: Point<2> = Point::from_coords([0, 0]);
+        assert_eq!(p.neighbors().count(), 8);
+    }
+
+    #[test]
+    fn neighbors_4d_is_eighty() {
+        let p: Point<4> = Point::from_coords([0, 0, 0, 0]);
+        assert_eq!(p.neighbors().count(), 3_usize.pow(4) - 1);
+    }
+
+    #[test]
+    fn integral_norm_is_exact() {
+        let p = Point3::new(3, 4, 0);
+        assert_eq!(p.integral_norm(), 5);
+        assert_eq!(Point3::new(3, 4, 1).integral_norm(), 5);
+    }
+
+    #[test]
+    fn chebyshev_distance_is_max_component() {
+        let a = Point3::new(0, 0, 0);
+        let b = Point3::new(3, -7, 2);
+        assert_eq!(a.chebyshev_distance(&b), 7);
+    }
+
+    #[test]
+    fn signum_is_per_axis_sign() {
+        let p = Point3::new(-5, 0, 12);
+        assert_eq!(p.signum(), Point3::new(-1, 0, 1));
+    }
+
+    #[test]
+    fn points_between_is_inclusive_on_diagonal() {
+        let a = Point3::new(0, 0, 0);
+        let b = Point3::new(3, 3, 0);
+        let points: Vec<_> = PointsBetween::new(a, b).collect();
+        assert_eq!(points.first(), Some(&a));
+        assert_eq!(points.last(), Some(&b));
+        assert_eq!(points.len(), 4);
+    }
+
+    #[test]
+    fn points_between_handles_shallow_slope() {
+        let a = Point3::new(0, 0, 0);
+        let b = Point3::new(9, 2, 0);
+        let points: Vec<_> = PointsBetween::new(a, b).collect();
+        assert_eq!(points.len(), 10);
+        assert_eq!(points.last(), Some(&b));
+    }
+
+    #[test]
+    fn rotate_left_and_right_are_inverses() {
+        let p = Point3::new(3, 1, 0);
+        assert_eq!(p.rotate_left().rotate_right(), p);
+        assert_eq!(p.rotate_left(), Point3::new(-1, 3, 0));
+        assert_eq!(p.rotate_right(), Point3::new(1, -3, 0));
+    }
+
+    #[test]
+    fn orientations_yields_24_distinct_points() {
+        let p = Point3::new(1, 2, 3);
+        let seen: std::collections::HashSet<_> = p.orientations().collect();
+        assert_eq!(seen.len(), 24);
+    }
+
+    #[test]
+    fn orientations_preserve_length() {
+        let p = Point3::new(1, 2, 3);
+        for oriented in p.orientations() {
+            assert_eq!(oriented.len_squared(), p.len_squared());
+        }
+    }
+
+    #[test]
+    fn turn_left_cycles_plane_directions() {
+        assert_eq!(Direction::PosX.turn_left(), Direction::NegY);
+        assert_eq!(Direction::NegY.turn_left(), Direction::NegX);
+        assert_eq!(Direction::NegX.turn_left(), Direction::PosY);
+        assert_eq!(Direction::PosY.turn_left(), Direction::PosX);
+    }
+
+    #[test]
+    fn turn_left_is_counter_clockwise_on_a_north_up_map() {
+        // East, turn left, face North (`-y` is north since `y` grows downward).
+        assert_eq!(Direction::PosX.turn_left(), Direction::NegY);
+    }
+
+    #[test]
+    fn turn_right_is_turn_left_inverse() {
+        for dir in Direction::all_plane_with_diagonals() {
+            assert_eq!(dir.turn_left().turn_right(), *dir);
+        }
+    }
+
+    #[test]
+    fn opposite_is_involution() {
+        for dir in Direction::all_plane_with_diagonals() {
+            assert_eq!(dir.opposite().opposite(), *dir);
+        }
+    }
+
+    #[test]
+    fn from_char_accepts_all_three_notations() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::NegY));
+        assert_eq!(Direction::from_char('N'), Some(Direction::NegY));
+        assert_eq!(Direction::from_char('^'), Some(Direction::NegY));
+        assert_eq!(Direction::from_char('R'), Some(Direction::PosX));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn step_moves_n_units_along_direction() {
+        let p = Point3::new(0, 0, 0);
+        assert_eq!(p.step(Direction::PosX, 3), Point3::new(3, 0, 0));
+        assert_eq!(p.step(Direction::PosXPosY, 2), Point3::new(2, 2, 0));
+    }
 }